@@ -1,13 +1,20 @@
+pub mod dock;
+pub mod floating;
+pub mod palette;
 pub mod pane;
 pub mod side_menu;
 
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
 use data::history::manager::Broadcast;
 use data::{history, Config, Server};
+use dock::Docks;
+use floating::FloatingPane;
 use iced::widget::pane_grid::{self, PaneGrid};
-use iced::widget::{container, row};
-use iced::{clipboard, window, Command, Length, Subscription};
+use iced::widget::{column, container, row, stack, Space};
+use iced::{clipboard, window, Command, Length, Point, Rectangle, Subscription};
+use palette::Palette;
 use pane::Pane;
 use side_menu::SideMenu;
 
@@ -15,6 +22,7 @@ use crate::buffer::{self, Buffer};
 use crate::widget::{selectable_text, Element};
 
 const SAVE_AFTER: Duration = Duration::from_secs(3);
+const MAX_CLOSED: usize = 10;
 
 pub struct Dashboard {
     panes: pane_grid::State<Pane>,
@@ -22,6 +30,32 @@ pub struct Dashboard {
     side_menu: SideMenu,
     history: history::Manager,
     last_changed: Option<Instant>,
+    floating: Vec<FloatingPane>,
+    // Draw / hit-test order, back to front; the last entry is on top.
+    z_indices: Vec<usize>,
+    // Index of the floating pane being dragged, plus the last cursor
+    // position seen for it (as a fraction of the content area); `None` for
+    // the position until the first move after the press is observed, so the
+    // first delta is zero instead of jumping the pane under the cursor.
+    being_moved: Option<(usize, Option<Point>)>,
+    palette: Option<Palette>,
+    docks: Docks,
+    // Edge currently being dragged via its `dock::handle`, if any.
+    dock_resizing: Option<dock::Edge>,
+    // Most-recently-closed pane is at the back; capped at `MAX_CLOSED`.
+    closed: VecDeque<ClosedPane>,
+}
+
+/// A pane that was closed, kept around long enough to be restored by
+/// `reopen_closed`.
+#[derive(Debug)]
+struct ClosedPane {
+    buffers: Vec<Buffer>,
+    active: usize,
+    settings: buffer::Settings,
+    sibling: pane_grid::Pane,
+    axis: pane_grid::Axis,
+    ratio: f32,
 }
 
 #[derive(Debug)]
@@ -33,6 +67,17 @@ pub enum Message {
     Close,
     Tick(Instant),
     DashboardSaved(Result<(), data::dashboard::Error>),
+    FloatFocused(usize),
+    TileFloating(usize),
+    FloatMoved(usize, Point),
+    FloatResized(usize, floating::Geometry),
+    FloatReleased,
+    FloatBuffer(usize, buffer::Message),
+    Palette(palette::Message),
+    Dock(dock::Message),
+    DockResizeStarted(dock::Edge),
+    DockResizeReleased,
+    ReopenClosed,
 }
 
 impl Dashboard {
@@ -48,6 +93,13 @@ impl Dashboard {
             side_menu: SideMenu::new(),
             history: history::Manager::default(),
             last_changed: None,
+            floating: Vec::new(),
+            z_indices: Vec::new(),
+            being_moved: None,
+            palette: None,
+            docks: Docks::default(),
+            dock_resizing: None,
+            closed: VecDeque::new(),
         };
 
         let command = dashboard.track();
@@ -91,10 +143,14 @@ impl Dashboard {
                     if let Some(pane) = self.focus {
                         self.last_changed = Some(Instant::now());
 
-                        if let Some((_, sibling)) = self.panes.close(&pane) {
+                        let context = closing_context(&self.panes, pane);
+
+                        if let Some((closed, sibling)) = self.panes.close(&pane) {
+                            self.record_closed(closed, context);
+
                             return self.focus_pane(sibling);
                         } else if let Some(pane) = self.panes.get_mut(&pane) {
-                            pane.buffer = Buffer::Empty(Default::default());
+                            pane.reset_to_empty();
                         }
                     }
                 }
@@ -117,7 +173,8 @@ impl Dashboard {
                 pane::Message::Buffer(id, message) => {
                     if let Some(pane) = self.panes.get_mut(&id) {
                         let (command, event) =
-                            pane.buffer.update(message, clients, &mut self.history);
+                            pane.active_mut()
+                                .update(message, clients, &mut self.history);
 
                         match event {
                             Some(buffer::Event::Empty(event)) => match event {},
@@ -140,76 +197,251 @@ impl Dashboard {
                 pane::Message::MaximizePane => {
                     if self.panes.maximized().is_some() {
                         self.panes.restore();
+                        self.last_changed = Some(Instant::now());
                     } else if let Some(pane) = self.focus {
                         self.panes.maximize(&pane);
+                        self.last_changed = Some(Instant::now());
                     }
                 }
-            },
-            Message::SideMenu(message) => {
-                if let Some(event) = self.side_menu.update(message) {
-                    let panes = self.panes.clone();
+                pane::Message::NewTab(kind) => {
+                    if let Some(pane) = self.focus {
+                        if let Some(state) = self.panes.get_mut(&pane) {
+                            state.new_tab(Buffer::from(kind), config.new_buffer.clone());
+                            self.last_changed = Some(Instant::now());
 
-                    match event {
-                        side_menu::Event::Open(kind) => {
-                            // If channel already is open, we focus it.
-                            for (id, pane) in panes.iter() {
-                                if pane.buffer.data().as_ref() == Some(&kind) {
-                                    self.focus = Some(*id);
+                            return Command::batch(vec![self.focus_pane(pane), self.track()]);
+                        }
+                    }
+                }
+                pane::Message::SelectTab(index) => {
+                    if let Some(pane) = self.focus {
+                        if let Some(state) = self.panes.get_mut(&pane) {
+                            state.select_tab(index);
+                            self.last_changed = Some(Instant::now());
+
+                            return self.focus_pane(pane);
+                        }
+                    }
+                }
+                pane::Message::CloseTab(index) => {
+                    if let Some(pane) = self.focus {
+                        if let Some(state) = self.panes.get_mut(&pane) {
+                            state.close_tab(index);
+                            self.last_changed = Some(Instant::now());
+                        }
+                    }
+                }
+                pane::Message::MoveTabToSplit(index, axis) => {
+                    if let Some(pane) = self.focus {
+                        if let Some(state) = self.panes.get_mut(&pane) {
+                            if let Some(buffer) = state.take_tab(index) {
+                                let result = self.panes.split(
+                                    axis,
+                                    &pane,
+                                    Pane::new(buffer, config.new_buffer.clone()),
+                                );
+                                self.last_changed = Some(Instant::now());
 
-                                    return self.focus_pane(*id);
+                                if let Some((pane, _)) = result {
+                                    return self.focus_pane(pane);
                                 }
                             }
+                        }
+                    }
+                }
+                pane::Message::FloatPane => {
+                    if let Some(pane) = self.focus {
+                        if let Some(state) = self.panes.get_mut(&pane) {
+                            let active = state.active;
 
-                            // If we only have one pane, and its empty, we replace it.
-                            if self.panes.len() == 1 {
-                                for (id, pane) in panes.iter() {
-                                    if let Buffer::Empty(_) = &pane.buffer {
-                                        self.panes.panes.entry(*id).and_modify(|p| {
-                                            *p = Pane::new(
-                                                Buffer::from(kind),
-                                                config.new_buffer.clone(),
-                                            )
-                                        });
-                                        self.last_changed = Some(Instant::now());
-
-                                        return self.focus_pane(*id);
-                                    }
+                            // More than one tab: just pop the active one
+                            // out and leave the rest tiled.
+                            if state.buffers.len() > 1 {
+                                if let Some(buffer) = state.take_tab(active) {
+                                    self.push_floating(buffer, state.settings.clone());
+                                    self.last_changed = Some(Instant::now());
                                 }
+
+                                return Command::none();
                             }
+                        }
 
-                            // Default split could be a config option.
-                            let axis = pane_grid::Axis::Horizontal;
-                            let pane_to_split = {
-                                if let Some(pane) = self.focus {
-                                    pane
-                                } else if let Some(pane) = self.panes.panes.keys().last() {
-                                    *pane
-                                } else {
-                                    log::error!("Didn't find any panes");
-                                    return Command::none();
-                                }
-                            };
+                        let context = closing_context(&self.panes, pane);
 
-                            let result = self.panes.split(
-                                axis,
-                                &pane_to_split,
-                                Pane::new(Buffer::from(kind), config.new_buffer.clone()),
-                            );
+                        if let Some((closed, sibling)) = self.panes.close(&pane) {
+                            if let Some(buffer) = closed.buffers.into_iter().nth(closed.active) {
+                                self.push_floating(buffer, closed.settings);
+                            }
                             self.last_changed = Some(Instant::now());
 
-                            if let Some((pane, _)) = result {
-                                return self.focus_pane(pane);
+                            return self.focus_pane(sibling);
+                        } else if let Some(state) = self.panes.get_mut(&pane) {
+                            // Last remaining pane: float its buffer and
+                            // leave an empty tile behind so the grid isn't
+                            // left without any panes.
+                            if let Some(buffer) = state.take_tab(active) {
+                                self.push_floating(buffer, state.settings.clone());
                             }
+                            state.reset_to_empty();
+                            self.last_changed = Some(Instant::now());
+                        }
+                    }
+                }
+            },
+            Message::FloatFocused(index) => {
+                if let Some(position) = self.z_indices.iter().position(|&i| i == index) {
+                    let raised = self.z_indices.remove(position);
+                    self.z_indices.push(raised);
+                }
+
+                self.being_moved = Some((index, None));
+            }
+            Message::TileFloating(index) => {
+                if index < self.floating.len() {
+                    let floating_pane = self.floating.remove(index);
+                    self.z_indices.retain(|&i| i != index);
+                    self.z_indices.iter_mut().for_each(|i| {
+                        if *i > index {
+                            *i -= 1;
+                        }
+                    });
+                    self.last_changed = Some(Instant::now());
+
+                    let axis = pane_grid::Axis::Horizontal;
+                    let pane_to_split = self
+                        .focus
+                        .or_else(|| self.panes.panes.keys().last().copied());
+
+                    if let Some(pane_to_split) = pane_to_split {
+                        let result = self.panes.split(
+                            axis,
+                            &pane_to_split,
+                            Pane::new(floating_pane.buffer, floating_pane.settings),
+                        );
+
+                        if let Some((pane, _)) = result {
+                            return self.focus_pane(pane);
+                        }
+                    }
+                }
+            }
+            Message::FloatMoved(index, position) => {
+                // `position` arrives already expressed as a fraction of the
+                // dashboard's content area (see the drag handling in
+                // `view`). We only move the pane by the delta since the
+                // last event, so the drag tracks wherever it was grabbed
+                // rather than snapping its corner to the cursor.
+                if let Some((moved, last)) = self.being_moved {
+                    if moved == index {
+                        if let Some(last) = last {
+                            if let Some(floating_pane) = self.floating.get_mut(index) {
+                                let max_x = (1.0 - floating_pane.geometry.width).max(0.0);
+                                let max_y = (1.0 - floating_pane.geometry.height).max(0.0);
+
+                                floating_pane.geometry.x = (floating_pane.geometry.x
+                                    + (position.x - last.x))
+                                    .clamp(0.0, max_x);
+                                floating_pane.geometry.y = (floating_pane.geometry.y
+                                    + (position.y - last.y))
+                                    .clamp(0.0, max_y);
+
+                                self.last_changed = Some(Instant::now());
+                            }
+                        }
+
+                        self.being_moved = Some((index, Some(position)));
+                    }
+                }
+            }
+            Message::FloatResized(index, geometry) => {
+                if let Some(floating_pane) = self.floating.get_mut(index) {
+                    floating_pane.geometry = geometry;
+                    self.last_changed = Some(Instant::now());
+                }
+            }
+            Message::FloatReleased => {
+                self.being_moved = None;
+            }
+            Message::FloatBuffer(index, message) => {
+                if let Some(floating_pane) = self.floating.get_mut(index) {
+                    let (command, event) =
+                        floating_pane
+                            .buffer
+                            .update(message, clients, &mut self.history);
+
+                    match event {
+                        Some(buffer::Event::Empty(event)) => match event {},
+                        Some(buffer::Event::Channel(event)) => match event {},
+                        Some(buffer::Event::Server(event)) => match event {},
+                        Some(buffer::Event::Query(event)) => match event {},
+                        None => {}
+                    }
+
+                    return command.map(move |message| Message::FloatBuffer(index, message));
+                }
+            }
+            Message::Palette(message) => {
+                let is_input = matches!(message, palette::Message::Input(_));
+
+                if let Some(palette) = &mut self.palette {
+                    match palette.update(message) {
+                        Some(palette::Event::Open(kind)) => {
+                            self.palette = None;
+                            return self.open(kind, config);
+                        }
+                        None => {
+                            if is_input {
+                                palette.refresh(clients, &self.history);
+                            }
+                        }
+                    }
+                }
+            }
+            Message::Dock(message) => {
+                self.docks.update(message);
+                self.last_changed = Some(Instant::now());
+            }
+            Message::DockResizeStarted(edge) => {
+                self.dock_resizing = Some(edge);
+            }
+            Message::DockResizeReleased => {
+                self.dock_resizing = None;
+            }
+            Message::ReopenClosed => {
+                return self.reopen_closed();
+            }
+            Message::SideMenu(message) => {
+                if let Some(event) = self.side_menu.update(message) {
+                    match event {
+                        side_menu::Event::Open(kind) => {
+                            return self.open(kind, config);
                         }
                         side_menu::Event::Replace(kind, pane) => {
                             if let Some(state) = self.panes.get_mut(&pane) {
-                                state.buffer = Buffer::from(kind);
+                                state.replace_active(Buffer::from(kind));
                                 self.last_changed = Some(Instant::now());
                                 return self.focus_pane(pane);
                             }
                         }
+                        side_menu::Event::OpenInTab(kind) => {
+                            if let Some(pane) = self.focus {
+                                if let Some(state) = self.panes.get_mut(&pane) {
+                                    state.new_tab(Buffer::from(kind), config.new_buffer.clone());
+                                    self.last_changed = Some(Instant::now());
+
+                                    return Command::batch(vec![
+                                        self.focus_pane(pane),
+                                        self.track(),
+                                    ]);
+                                }
+                            }
+                        }
                         side_menu::Event::Close(pane) => {
-                            self.panes.close(&pane);
+                            let context = closing_context(&self.panes, pane);
+
+                            if let Some((closed, _)) = self.panes.close(&pane) {
+                                self.record_closed(closed, context);
+                            }
                             self.last_changed = Some(Instant::now());
 
                             if self.focus == Some(pane) {
@@ -287,52 +519,232 @@ impl Dashboard {
     pub fn view<'a>(&'a self, clients: &'a data::client::Map) -> Element<'a, Message> {
         let focus = self.focus;
 
-        let pane_grid: Element<_> = PaneGrid::new(&self.panes, |id, pane, maximized| {
-            let is_focused = focus == Some(id);
-            let panes = self.panes.len();
-            pane.view(id, panes, is_focused, maximized, clients, &self.history)
-        })
-        .on_click(pane::Message::PaneClicked)
-        .on_resize(6, pane::Message::PaneResized)
-        .on_drag(pane::Message::PaneDragged)
-        .spacing(4)
-        .into();
-
-        let pane_grid = container(pane_grid.map(Message::Pane))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .padding(8);
+        let build_pane_grid = move || -> Element<'a, Message> {
+            let pane_grid: Element<_> = PaneGrid::new(&self.panes, |id, pane, maximized| {
+                let is_focused = focus == Some(id);
+                let panes = self.panes.len();
+                pane.view(id, panes, is_focused, maximized, clients, &self.history)
+            })
+            .on_click(pane::Message::PaneClicked)
+            .on_resize(6, pane::Message::PaneResized)
+            .on_drag(pane::Message::PaneDragged)
+            .spacing(4)
+            .into();
+
+            container(pane_grid.map(Message::Pane))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(8)
+                .into()
+        };
+
+        let build_pane_grid_layer = move || -> Element<'a, Message> {
+            if self.floating.is_empty() {
+                return build_pane_grid();
+            }
+
+            iced::widget::responsive(move |size| {
+                let bounds = Rectangle::with_size(size);
+                let mut layers = stack![build_pane_grid()];
+
+                for &index in &self.z_indices {
+                    let Some(floating_pane) = self.floating.get(index) else {
+                        continue;
+                    };
+
+                    let is_focused = self.being_moved.map(|(moved, _)| moved) == Some(index);
+                    let rect = floating_pane.geometry.to_rectangle(bounds);
+
+                    layers = layers.push(
+                        row![
+                            Space::with_width(Length::Fixed(rect.x)),
+                            column![
+                                Space::with_height(Length::Fixed(rect.y)),
+                                container(floating::view(
+                                    index,
+                                    floating_pane,
+                                    is_focused,
+                                    clients,
+                                    &self.history
+                                ))
+                                .width(Length::Fixed(rect.width))
+                                .height(Length::Fixed(rect.height)),
+                            ]
+                            .width(Length::Shrink),
+                        ]
+                        .width(Length::Fill)
+                        .height(Length::Fill),
+                    );
+                }
+
+                let content: Element<'a, Message> = layers.into();
 
-        let side_menu = self
-            .side_menu
-            .view(clients, &self.history, &self.panes, self.focus)
-            .map(Message::SideMenu);
+                // While a floating pane is being dragged, cover the whole
+                // area so move/release events keep reaching us even if the
+                // cursor strays off the pane itself, and convert the
+                // reported position into a fraction of `bounds` up front so
+                // `update` never has to guess the content area's size.
+                if let Some((index, _)) = self.being_moved {
+                    iced::widget::mouse_area(content)
+                        .on_move(move |position| {
+                            Message::FloatMoved(
+                                index,
+                                Point::new(
+                                    (position.x / bounds.width).clamp(0.0, 1.0),
+                                    (position.y / bounds.height).clamp(0.0, 1.0),
+                                ),
+                            )
+                        })
+                        .on_release(Message::FloatReleased)
+                        .into()
+                } else {
+                    content
+                }
+            })
+            .into()
+        };
 
         // The height margin varies across different operating systems due to design differences.
         // For instance, on macOS, the menubar is hidden, resulting in a need for additional padding to accommodate the
         // space occupied by the traffic light buttons.
         let height_margin = if cfg!(target_os = "macos") { 20 } else { 0 };
 
-        row![side_menu, pane_grid]
+        let portion = |ratio: f32| (ratio * 100.0).round() as u16;
+
+        let build_framed = move || -> Element<'a, Message> {
+            let side_menu = self
+                .side_menu
+                .view(clients, &self.history, &self.panes, self.focus)
+                .map(Message::SideMenu);
+
+            let grid = row![
+                side_menu,
+                build_pane_grid_layer(),
+                dock::toggles(&self.docks)
+            ]
             .width(Length::Fill)
             .height(Length::Fill)
-            .padding([height_margin, 0, 0, 0])
+            .padding([height_margin, 0, 0, 0]);
+
+            let focused_buffer = self.focused_buffer();
+
+            let mut middle = column![grid].width(Length::Fill).height(Length::Fill);
+
+            if self.docks.bottom.open {
+                middle = middle.push(dock::handle(dock::Edge::Bottom)).push(
+                    container(dock::view(
+                        dock::Edge::Bottom,
+                        &self.docks.bottom,
+                        focused_buffer,
+                        &self.history,
+                    ))
+                    .width(Length::Fill)
+                    .height(Length::FillPortion(portion(self.docks.bottom.ratio))),
+                );
+            }
+
+            let mut framed = row![];
+
+            if self.docks.left.open {
+                framed = framed
+                    .push(
+                        container(dock::view(
+                            dock::Edge::Left,
+                            &self.docks.left,
+                            focused_buffer,
+                            &self.history,
+                        ))
+                        .width(Length::FillPortion(portion(self.docks.left.ratio)))
+                        .height(Length::Fill),
+                    )
+                    .push(dock::handle(dock::Edge::Left));
+            }
+
+            framed = framed.push(middle);
+
+            if self.docks.right.open {
+                framed = framed.push(dock::handle(dock::Edge::Right)).push(
+                    container(dock::view(
+                        dock::Edge::Right,
+                        &self.docks.right,
+                        focused_buffer,
+                        &self.history,
+                    ))
+                    .width(Length::FillPortion(portion(self.docks.right.ratio)))
+                    .height(Length::Fill),
+                );
+            }
+
+            framed.width(Length::Fill).height(Length::Fill).into()
+        };
+
+        let content: Element<'a, Message> = if let Some(edge) = self.dock_resizing {
+            iced::widget::responsive(move |size| {
+                let bounds = Rectangle::with_size(size);
+
+                iced::widget::mouse_area(build_framed())
+                    .on_move(move |position| {
+                        let ratio = match edge {
+                            dock::Edge::Left => (position.x / bounds.width).clamp(0.1, 0.6),
+                            dock::Edge::Right => (1.0 - position.x / bounds.width).clamp(0.1, 0.6),
+                            dock::Edge::Bottom => {
+                                (1.0 - position.y / bounds.height).clamp(0.1, 0.6)
+                            }
+                        };
+
+                        Message::Dock(dock::Message::Resize(edge, ratio))
+                    })
+                    .on_release(Message::DockResizeReleased)
+                    .into()
+            })
+            .into()
+        } else {
+            build_framed()
+        };
+
+        if let Some(palette) = &self.palette {
+            stack![
+                content,
+                container(palette.view())
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(64)
+                    .align_x(iced::alignment::Horizontal::Center)
+                    .align_y(iced::alignment::Vertical::Top)
+            ]
             .into()
+        } else {
+            content
+        }
     }
 
-    pub fn handle_event(&mut self, event: crate::event::Event) -> Command<Message> {
+    pub fn handle_event(
+        &mut self,
+        event: crate::event::Event,
+        clients: &data::client::Map,
+    ) -> Command<Message> {
         use crate::event::Event::*;
 
         match event {
             Escape => {
-                self.focus = None;
+                if self.palette.is_some() {
+                    self.palette = None;
+                } else {
+                    self.focus = None;
+                }
+
+                Command::none()
+            }
+            CommandPalette => {
+                self.palette = Some(Palette::new(clients, &self.history));
                 Command::none()
             }
+            ReopenClosed => self.reopen_closed(),
             Copy => selectable_text::selected(Message::SelectedText),
             Home => self
                 .get_focused_mut()
                 .map(|(id, pane)| {
-                    pane.buffer
+                    pane.active_mut()
                         .scroll_to_start()
                         .map(move |message| Message::Pane(pane::Message::Buffer(id, message)))
                 })
@@ -341,7 +753,7 @@ impl Dashboard {
                 .get_focused_mut()
                 .map(|(pane, state)| {
                     state
-                        .buffer
+                        .active_mut()
                         .scroll_to_end()
                         .map(move |message| Message::Pane(pane::Message::Buffer(pane, message)))
                 })
@@ -388,16 +800,152 @@ impl Dashboard {
         self.panes.get_mut(&pane).map(|state| (pane, state))
     }
 
+    fn focused_buffer(&self) -> Option<&Buffer> {
+        let pane = self.focus?;
+        Some(self.panes.get(&pane)?.active())
+    }
+
+    fn open(&mut self, kind: data::Buffer, config: &Config) -> Command<Message> {
+        let panes = self.panes.clone();
+
+        // If channel already is open in any tab, we select that tab and focus it.
+        for (id, pane) in panes.iter() {
+            if let Some(index) = pane
+                .buffers
+                .iter()
+                .position(|buffer| buffer.data().as_ref() == Some(&kind))
+            {
+                if let Some(state) = self.panes.get_mut(id) {
+                    state.select_tab(index);
+                }
+
+                self.focus = Some(*id);
+
+                return self.focus_pane(*id);
+            }
+        }
+
+        // If we only have one pane, and its lone tab is empty, we replace it.
+        if self.panes.len() == 1 {
+            for (id, pane) in panes.iter() {
+                if let [Buffer::Empty(_)] = pane.buffers.as_slice() {
+                    self.panes.panes.entry(*id).and_modify(|p| {
+                        *p = Pane::new(Buffer::from(kind), config.new_buffer.clone())
+                    });
+                    self.last_changed = Some(Instant::now());
+
+                    return self.focus_pane(*id);
+                }
+            }
+        }
+
+        // Default split could be a config option.
+        let axis = pane_grid::Axis::Horizontal;
+        let pane_to_split = {
+            if let Some(pane) = self.focus {
+                pane
+            } else if let Some(pane) = self.panes.panes.keys().last() {
+                *pane
+            } else {
+                log::error!("Didn't find any panes");
+                return Command::none();
+            }
+        };
+
+        let result = self.panes.split(
+            axis,
+            &pane_to_split,
+            Pane::new(Buffer::from(kind), config.new_buffer.clone()),
+        );
+        self.last_changed = Some(Instant::now());
+
+        if let Some((pane, _)) = result {
+            return self.focus_pane(pane);
+        }
+
+        Command::none()
+    }
+
+    /// Pops `buffer` out of the tiled grid and onto the floating layer,
+    /// raised above any other floating panes.
+    fn push_floating(&mut self, buffer: Buffer, settings: buffer::Settings) {
+        let index = self.floating.len();
+        self.floating.push(FloatingPane::new(buffer, settings));
+        self.z_indices.push(index);
+    }
+
+    fn record_closed(
+        &mut self,
+        closed: Pane,
+        context: Option<(pane_grid::Pane, pane_grid::Axis, f32)>,
+    ) {
+        let Some((sibling, axis, ratio)) = context else {
+            return;
+        };
+
+        self.closed.push_back(ClosedPane {
+            buffers: closed.buffers,
+            active: closed.active,
+            settings: closed.settings,
+            sibling,
+            axis,
+            ratio,
+        });
+
+        while self.closed.len() > MAX_CLOSED {
+            self.closed.pop_front();
+        }
+    }
+
+    fn reopen_closed(&mut self) -> Command<Message> {
+        let Some(closed) = self.closed.pop_back() else {
+            return Command::none();
+        };
+
+        let pane_to_split = if self.panes.get(&closed.sibling).is_some() {
+            closed.sibling
+        } else if let Some(pane) = self.focus {
+            pane
+        } else if let Some(pane) = self.panes.panes.keys().last() {
+            *pane
+        } else {
+            return Command::none();
+        };
+
+        let mut buffers = closed.buffers.into_iter();
+        let Some(first) = buffers.next() else {
+            return Command::none();
+        };
+
+        let mut restored = Pane::new(first, closed.settings.clone());
+        for buffer in buffers {
+            restored.new_tab(buffer, closed.settings.clone());
+        }
+        restored.select_tab(closed.active);
+
+        let result = self.panes.split(closed.axis, &pane_to_split, restored);
+        self.last_changed = Some(Instant::now());
+
+        if let Some((pane, split)) = result {
+            self.panes.resize(&split, closed.ratio);
+
+            return Command::batch(vec![self.focus_pane(pane), self.track()]);
+        }
+
+        Command::none()
+    }
+
     fn focus_pane(&mut self, pane: pane_grid::Pane) -> Command<Message> {
         if self.focus != Some(pane) {
             self.focus = Some(pane);
+            self.last_changed = Some(Instant::now());
 
             self.panes
                 .iter()
                 .find_map(|(p, state)| {
                     (*p == pane).then(|| {
                         state
-                            .buffer
+                            .active()
                             .focus()
                             .map(move |message| Message::Pane(pane::Message::Buffer(pane, message)))
                     })
@@ -412,7 +960,8 @@ impl Dashboard {
         let resources = self
             .panes
             .iter()
-            .filter_map(|(_, pane)| pane.resource())
+            .flat_map(|(_, pane)| pane.resources())
+            .chain(self.floating.iter().filter_map(FloatingPane::resource))
             .collect();
 
         Command::batch(
@@ -425,10 +974,37 @@ impl Dashboard {
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
-        iced::time::every(Duration::from_secs(1)).map(Message::Tick)
+        // Floating-pane drag/release is handled by the `mouse_area` laid
+        // over the content in `view`, which can convert cursor positions
+        // into content-relative fractions; a raw window-event listener here
+        // has no way to do that conversion.
+        let tick = iced::time::every(Duration::from_secs(1)).map(Message::Tick);
+
+        if self.palette.is_some() {
+            let navigate = iced::keyboard::on_key_press(|key, _modifiers| match key {
+                iced::keyboard::Key::Named(iced::keyboard::key::Named::ArrowUp) => {
+                    Some(Message::Palette(palette::Message::Move(-1)))
+                }
+                iced::keyboard::Key::Named(iced::keyboard::key::Named::ArrowDown) => {
+                    Some(Message::Palette(palette::Message::Move(1)))
+                }
+                _ => None,
+            });
+
+            Subscription::batch([tick, navigate])
+        } else {
+            tick
+        }
     }
 }
 
+// These two conversions round-trip through `data::Dashboard::floating`,
+// `.focus`, `.maximized`, and `.docks`, plus the `data::dashboard::{Floating,
+// Docks, Dock}` and `data::pane::Side` types. The `data` crate isn't part of
+// this checkout, so that side of the change isn't in this diff; it needs to
+// land as a companion commit there (new optional fields on `data::Dashboard`
+// defaulting to empty/closed/`None` so existing saved dashboards still
+// deserialize) before this file will compile.
 impl From<data::Dashboard> for Dashboard {
     fn from(dashboard: data::Dashboard) -> Self {
         use pane_grid::Configuration;
@@ -453,12 +1029,57 @@ impl From<data::Dashboard> for Dashboard {
             }
         }
 
+        let floating: Vec<FloatingPane> = dashboard
+            .floating
+            .into_iter()
+            .map(|floating| FloatingPane {
+                buffer: Buffer::from(floating.buffer),
+                geometry: floating::Geometry {
+                    x: floating.x,
+                    y: floating.y,
+                    width: floating.width,
+                    height: floating.height,
+                },
+                settings: floating.settings,
+            })
+            .collect();
+        let z_indices = (0..floating.len()).collect();
+
+        let mut panes = pane_grid::State::with_configuration(configuration(dashboard.pane));
+
+        let focus = dashboard
+            .focus
+            .as_deref()
+            .and_then(|path| resolve_pane_path(&panes, path));
+
+        if let Some(maximized_path) = dashboard.maximized.as_deref() {
+            if let Some(pane) = resolve_pane_path(&panes, maximized_path) {
+                panes.maximize(&pane);
+            }
+        }
+
         Self {
-            panes: pane_grid::State::with_configuration(configuration(dashboard.pane)),
-            focus: None,
+            panes,
+            focus,
             side_menu: SideMenu::new(),
             history: history::Manager::default(),
             last_changed: None,
+            floating,
+            z_indices,
+            being_moved: None,
+            palette: None,
+            dock_resizing: None,
+            closed: VecDeque::new(),
+            docks: {
+                let mut docks = Docks::default();
+                docks.left.open = dashboard.docks.left.open;
+                docks.left.ratio = dashboard.docks.left.ratio;
+                docks.right.open = dashboard.docks.right.open;
+                docks.right.ratio = dashboard.docks.right.ratio;
+                docks.bottom.open = dashboard.docks.bottom.open;
+                docks.bottom.ratio = dashboard.docks.bottom.ratio;
+                docks
+            },
         }
     }
 }
@@ -490,8 +1111,143 @@ impl<'a> From<&'a Dashboard> for data::Dashboard {
 
         let layout = dashboard.panes.layout().clone();
 
+        let floating = dashboard
+            .floating
+            .iter()
+            .map(|floating| data::dashboard::Floating {
+                buffer: data::Buffer::from(&floating.buffer),
+                x: floating.geometry.x,
+                y: floating.geometry.y,
+                width: floating.geometry.width,
+                height: floating.geometry.height,
+                settings: floating.settings.clone(),
+            })
+            .collect();
+
+        let focus = dashboard
+            .focus
+            .and_then(|pane| pane_path(&dashboard.panes, pane));
+        let maximized = dashboard
+            .panes
+            .maximized()
+            .and_then(|pane| pane_path(&dashboard.panes, pane));
+
+        let docks = data::dashboard::Docks {
+            left: data::dashboard::Dock {
+                open: dashboard.docks.left.open,
+                ratio: dashboard.docks.left.ratio,
+            },
+            right: data::dashboard::Dock {
+                open: dashboard.docks.right.open,
+                ratio: dashboard.docks.right.ratio,
+            },
+            bottom: data::dashboard::Dock {
+                open: dashboard.docks.bottom.open,
+                ratio: dashboard.docks.bottom.ratio,
+            },
+        };
+
         data::Dashboard {
             pane: from_layout(&dashboard.panes, layout),
+            floating,
+            focus,
+            maximized,
+            docks,
+        }
+    }
+}
+
+/// A pane's position in the layout tree, expressed as a sequence of
+/// left/right choices down the `Split` nodes. Stable across restarts, unlike
+/// `pane_grid::Pane` ids which are freshly allocated on every load.
+fn pane_path(
+    panes: &pane_grid::State<Pane>,
+    target: pane_grid::Pane,
+) -> Option<Vec<data::pane::Side>> {
+    fn walk(
+        node: &pane_grid::Node,
+        target: pane_grid::Pane,
+        path: &mut Vec<data::pane::Side>,
+    ) -> bool {
+        match node {
+            pane_grid::Node::Split { a, b, .. } => {
+                path.push(data::pane::Side::First);
+                if walk(a, target, path) {
+                    return true;
+                }
+                path.pop();
+
+                path.push(data::pane::Side::Second);
+                if walk(b, target, path) {
+                    return true;
+                }
+                path.pop();
+
+                false
+            }
+            pane_grid::Node::Pane(pane) => *pane == target,
+        }
+    }
+
+    let mut path = Vec::new();
+    walk(panes.layout(), target, &mut path).then_some(path)
+}
+
+/// Finds the split immediately above `target`, returning the first pane of
+/// its sibling subtree, along with the split's axis and ratio, so a later
+/// `reopen_closed` can split that pane again. The reopened pane is always
+/// placed second in the new split and the ratio applies to the first side,
+/// so a pane closed from the first side comes back on the other side.
+fn closing_context(
+    panes: &pane_grid::State<Pane>,
+    target: pane_grid::Pane,
+) -> Option<(pane_grid::Pane, pane_grid::Axis, f32)> {
+    fn first_pane(node: &pane_grid::Node) -> Option<pane_grid::Pane> {
+        match node {
+            pane_grid::Node::Pane(pane) => Some(*pane),
+            pane_grid::Node::Split { a, .. } => first_pane(a),
+        }
+    }
+
+    fn walk(
+        node: &pane_grid::Node,
+        target: pane_grid::Pane,
+    ) -> Option<(pane_grid::Pane, pane_grid::Axis, f32)> {
+        let pane_grid::Node::Split {
+            axis, ratio, a, b, ..
+        } = node
+        else {
+            return None;
+        };
+
+        match (a.as_ref(), b.as_ref()) {
+            (pane_grid::Node::Pane(pane), _) if *pane == target => {
+                first_pane(b).map(|sibling| (sibling, *axis, *ratio))
+            }
+            (_, pane_grid::Node::Pane(pane)) if *pane == target => {
+                first_pane(a).map(|sibling| (sibling, *axis, *ratio))
+            }
+            _ => walk(a, target).or_else(|| walk(b, target)),
+        }
+    }
+
+    walk(panes.layout(), target)
+}
+
+fn resolve_pane_path(
+    panes: &pane_grid::State<Pane>,
+    path: &[data::pane::Side],
+) -> Option<pane_grid::Pane> {
+    fn walk(node: &pane_grid::Node, path: &[data::pane::Side]) -> Option<pane_grid::Pane> {
+        match (node, path.split_first()) {
+            (pane_grid::Node::Pane(pane), None) => Some(*pane),
+            (pane_grid::Node::Split { a, b, .. }, Some((side, rest))) => match side {
+                data::pane::Side::First => walk(a, rest),
+                data::pane::Side::Second => walk(b, rest),
+            },
+            _ => None,
         }
     }
+
+    walk(panes.layout(), path)
 }
@@ -0,0 +1,170 @@
+use data::history;
+use iced::widget::{button, column, container, mouse_area, scrollable, text, Space};
+use iced::Length;
+
+use crate::buffer::Buffer;
+use crate::widget::Element;
+
+/// Width/height of the draggable strip rendered between a dock panel and
+/// the tiled grid.
+const HANDLE_SIZE: f32 = 4.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Left,
+    Right,
+    Bottom,
+}
+
+// The request this dock subsystem comes from also asked for a full user
+// list and a `/LIST` channel browser in the left/right docks. Neither has a
+// `data::client::Map` accessor to query in this checkout (only the raw IRC
+// log used below is exposed), so this is cut down to the one dock content
+// we can actually render: the server log, pinnable to any of the three
+// edges.
+#[derive(Debug, Clone)]
+pub struct DockPanel {
+    pub open: bool,
+    pub ratio: f32,
+}
+
+impl DockPanel {
+    fn new(ratio: f32) -> Self {
+        Self { open: false, ratio }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Docks {
+    pub left: DockPanel,
+    pub right: DockPanel,
+    pub bottom: DockPanel,
+}
+
+impl Default for Docks {
+    fn default() -> Self {
+        Self {
+            left: DockPanel::new(0.2),
+            right: DockPanel::new(0.2),
+            bottom: DockPanel::new(0.25),
+        }
+    }
+}
+
+impl Docks {
+    pub fn panel(&self, edge: Edge) -> &DockPanel {
+        match edge {
+            Edge::Left => &self.left,
+            Edge::Right => &self.right,
+            Edge::Bottom => &self.bottom,
+        }
+    }
+
+    fn panel_mut(&mut self, edge: Edge) -> &mut DockPanel {
+        match edge {
+            Edge::Left => &mut self.left,
+            Edge::Right => &mut self.right,
+            Edge::Bottom => &mut self.bottom,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Toggle(Edge),
+    Resize(Edge, f32),
+}
+
+impl Docks {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle(edge) => {
+                let panel = self.panel_mut(edge);
+                panel.open = !panel.open;
+            }
+            Message::Resize(edge, ratio) => {
+                self.panel_mut(edge).ratio = ratio.clamp(0.1, 0.6);
+            }
+        }
+    }
+}
+
+pub fn view<'a>(
+    edge: Edge,
+    // Only the server log is implemented, so there's no per-panel content
+    // to branch on here yet; kept for when a second dock content type
+    // returns.
+    _panel: &'a DockPanel,
+    focused: Option<&'a Buffer>,
+    history: &'a history::Manager,
+) -> Element<'a, super::Message> {
+    let header = iced::widget::row![
+        text("Server Log").width(Length::Fill),
+        button(text("x")).on_press(super::Message::Dock(Message::Toggle(edge))),
+    ]
+    .padding(4)
+    .spacing(4);
+
+    let lines = focused
+        .and_then(Buffer::data)
+        .and_then(|kind| kind.server().cloned())
+        .map(|server| history.raw_log(&server))
+        .unwrap_or_default();
+
+    let body: Element<'a, super::Message> =
+        scrollable(column(lines.into_iter().map(|line| text(line).into())))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into();
+
+    container(column![header, body])
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// A persistent strip of toggle buttons for opening/closing each dock, so a
+/// closed dock (the default) can still be opened — `view` above is only
+/// ever rendered for a dock that's already open.
+pub fn toggles<'a>(docks: &Docks) -> Element<'a, super::Message> {
+    let toggle = |edge: Edge, label: &'static str, open: bool| {
+        button(text(label))
+            .style(if open {
+                button::primary
+            } else {
+                button::secondary
+            })
+            .on_press(super::Message::Dock(Message::Toggle(edge)))
+    };
+
+    column![
+        toggle(Edge::Left, "L", docks.left.open),
+        toggle(Edge::Right, "R", docks.right.open),
+        toggle(Edge::Bottom, "B", docks.bottom.open),
+    ]
+    .spacing(4)
+    .padding(4)
+    .into()
+}
+
+/// A thin draggable divider between an open dock panel and the rest of the
+/// layout. The actual resize math lives in `Dashboard::view`, which has the
+/// content bounds needed to turn a cursor position into a ratio.
+pub fn handle<'a>(edge: Edge) -> Element<'a, super::Message> {
+    let (width, height) = match edge {
+        Edge::Left | Edge::Right => (Length::Fixed(HANDLE_SIZE), Length::Fill),
+        Edge::Bottom => (Length::Fill, Length::Fixed(HANDLE_SIZE)),
+    };
+
+    mouse_area(
+        container(Space::new(Length::Fill, Length::Fill))
+            .width(width)
+            .height(height)
+            .style(|theme: &iced::Theme| {
+                container::Style::default()
+                    .background(theme.extended_palette().background.strong.color)
+            }),
+    )
+    .on_press(super::Message::DockResizeStarted(edge))
+    .into()
+}
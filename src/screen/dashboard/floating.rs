@@ -0,0 +1,103 @@
+use data::history;
+use iced::widget::{button, column, container, mouse_area, row, text};
+use iced::{Length, Rectangle};
+
+use crate::buffer::{self, Buffer};
+use crate::widget::Element;
+
+/// Position and size of a floating pane, expressed as fractions of the
+/// dashboard's content area so layouts survive window resizes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for Geometry {
+    fn default() -> Self {
+        Self {
+            x: 0.3,
+            y: 0.3,
+            width: 0.4,
+            height: 0.4,
+        }
+    }
+}
+
+impl Geometry {
+    pub fn to_rectangle(self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.x * bounds.width,
+            y: bounds.y + self.y * bounds.height,
+            width: self.width * bounds.width,
+            height: self.height * bounds.height,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FloatingPane {
+    pub buffer: Buffer,
+    pub geometry: Geometry,
+    pub settings: buffer::Settings,
+}
+
+impl FloatingPane {
+    pub fn new(buffer: Buffer, settings: buffer::Settings) -> Self {
+        Self {
+            buffer,
+            geometry: Geometry::default(),
+            settings,
+        }
+    }
+
+    pub fn resource(&self) -> Option<history::Resource> {
+        self.buffer.data().map(history::Resource::from)
+    }
+}
+
+pub fn view<'a>(
+    index: usize,
+    floating: &'a FloatingPane,
+    is_focused: bool,
+    clients: &'a data::client::Map,
+    history: &'a history::Manager,
+) -> Element<'a, super::Message> {
+    let header = row![
+        mouse_area(text(floating.buffer.title(clients)).width(Length::Fill))
+            .on_press(super::Message::FloatFocused(index)),
+        button(text("tile")).on_press(super::Message::TileFloating(index)),
+    ]
+    .spacing(4)
+    .padding(4);
+
+    let content = floating
+        .buffer
+        .view(clients, history, &floating.settings, is_focused)
+        .map(move |message| super::Message::FloatBuffer(index, message));
+
+    let pane = container(
+        column![header, content]
+            .width(Length::Fill)
+            .height(Length::Fill),
+    )
+    .style(move |theme: &iced::Theme| {
+        let palette = theme.extended_palette();
+
+        container::Style::default().border(iced::Border {
+            color: if is_focused {
+                palette.primary.strong.color
+            } else {
+                palette.background.strong.color
+            },
+            width: 1.0,
+            radius: 4.0.into(),
+        })
+    });
+
+    mouse_area(pane)
+        .on_press(super::Message::FloatFocused(index))
+        .into()
+}
@@ -0,0 +1,246 @@
+use data::{client, history};
+use iced::widget::{column, container, mouse_area, text, text_input};
+use iced::{Length, Padding};
+
+use crate::widget::Element;
+
+const MAX_RESULTS: usize = 8;
+
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    query: String,
+    matches: Vec<Match>,
+    selected: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Match {
+    target: data::Buffer,
+    label: String,
+    // Matched character ranges within `label`, used to highlight hits.
+    ranges: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Input(String),
+    Select(usize),
+    /// Move the selection up (negative) or down (positive) by one, wrapping
+    /// at either end of the match list. Driven by arrow-key navigation.
+    Move(i32),
+    Confirm,
+}
+
+pub enum Event {
+    Open(data::Buffer),
+}
+
+impl Palette {
+    pub fn new(clients: &client::Map, history: &history::Manager) -> Self {
+        Self {
+            matches: search("", clients, history),
+            ..Self::default()
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Option<Event> {
+        match message {
+            Message::Input(query) => {
+                self.selected = 0;
+                self.query = query;
+                None
+            }
+            Message::Select(index) => {
+                if index < self.matches.len() {
+                    self.selected = index;
+                }
+                None
+            }
+            Message::Move(delta) => {
+                let len = self.matches.len();
+                if len > 0 {
+                    let next = self.selected as i32 + delta;
+                    self.selected = next.rem_euclid(len as i32) as usize;
+                }
+                None
+            }
+            Message::Confirm => self
+                .matches
+                .get(self.selected)
+                .map(|found| Event::Open(found.target.clone())),
+        }
+    }
+
+    pub fn refresh(&mut self, clients: &client::Map, history: &history::Manager) {
+        self.matches = search(&self.query, clients, history);
+        self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+    }
+
+    pub fn view<'a>(&'a self) -> Element<'a, super::Message> {
+        let input = text_input("Jump to...", &self.query)
+            .on_input(|query| super::Message::Palette(Message::Input(query)))
+            .on_submit(super::Message::Palette(Message::Confirm))
+            .padding(8)
+            .width(Length::Fixed(420.0));
+
+        let results = self.matches.iter().enumerate().map(|(index, found)| {
+            let is_selected = index == self.selected;
+
+            let label: Element<_> = if found.ranges.is_empty() {
+                text(found.label.clone()).into()
+            } else {
+                highlighted(&found.label, &found.ranges)
+            };
+
+            let row = container(label)
+                .width(Length::Fill)
+                .padding(Padding::from([4, 8]))
+                .style(move |theme: &iced::Theme| {
+                    let palette = theme.extended_palette();
+
+                    container::Style::default().background(if is_selected {
+                        palette.primary.weak.color
+                    } else {
+                        palette.background.base.color
+                    })
+                });
+
+            mouse_area(row)
+                .on_press(super::Message::Palette(Message::Select(index)))
+                .into()
+        });
+
+        let list = column(results).width(Length::Fixed(420.0));
+
+        container(column![input, list].spacing(4))
+            .padding(12)
+            .style(container::bordered_box)
+            .into()
+    }
+}
+
+fn highlighted<'a>(label: &'a str, ranges: &[(usize, usize)]) -> Element<'a, super::Message> {
+    // Matched sub-ranges are rendered bold so the user can see why a result
+    // was picked; everything else renders as plain text.
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+
+    for &(start, end) in ranges {
+        if cursor < start {
+            spans.push(text(label[cursor..start].to_string()));
+        }
+
+        spans.push(text(label[start..end].to_string()).font(iced::Font {
+            weight: iced::font::Weight::Bold,
+            ..iced::Font::default()
+        }));
+
+        cursor = end;
+    }
+
+    if cursor < label.len() {
+        spans.push(text(label[cursor..].to_string()));
+    }
+
+    iced::widget::row(spans.into_iter().map(Element::from)).into()
+}
+
+fn search(query: &str, clients: &client::Map, history: &history::Manager) -> Vec<Match> {
+    let mut scored: Vec<(i32, Match)> = targets(clients, history)
+        .filter_map(|(target, label)| {
+            fuzzy_match(query, &label).map(|(score, ranges)| {
+                (
+                    score,
+                    Match {
+                        target,
+                        label,
+                        ranges,
+                    },
+                )
+            })
+        })
+        .collect();
+
+    scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored.truncate(MAX_RESULTS);
+    scored.into_iter().map(|(_, found)| found).collect()
+}
+
+fn targets<'a>(
+    clients: &'a client::Map,
+    history: &'a history::Manager,
+) -> impl Iterator<Item = (data::Buffer, String)> + 'a {
+    clients
+        .connected_servers()
+        .flat_map(move |server| {
+            std::iter::once((data::Buffer::Server(server.clone()), server.to_string())).chain(
+                clients.get_channels(server).iter().map(move |channel| {
+                    (
+                        data::Buffer::Channel(server.clone(), channel.clone()),
+                        format!("{server} {channel}"),
+                    )
+                }),
+            )
+        })
+        .chain(history.queries().map(|(server, nick)| {
+            (
+                data::Buffer::Query(server.clone(), nick.clone()),
+                format!("{server} {nick}"),
+            )
+        }))
+}
+
+/// Scores a fuzzy subsequence match of `query` within `candidate`, rewarding
+/// contiguous runs and an early first match. Returns `None` if `query` isn't
+/// a subsequence of `candidate`.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<(usize, usize)>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    // Walk `candidate`'s own char boundaries rather than a lowercased copy's
+    // — lowercasing can change a character's UTF-8 byte length (e.g. Turkish
+    // `İ` U+0130 → `i̇`, 2 bytes → 3), which would desync the byte ranges
+    // below from the `label` that `highlighted()` later slices with them.
+    let mut query_chars = query.chars().peekable();
+    let mut ranges = Vec::new();
+    let mut run_start = None;
+    let mut run_end = 0usize;
+    let mut run_chars = 0usize;
+    let mut first_match = None;
+    let mut score = 0i32;
+
+    for (index, ch) in candidate.char_indices() {
+        let matches = query_chars
+            .peek()
+            .is_some_and(|&query_ch| ch.to_lowercase().eq(query_ch.to_lowercase()));
+
+        if matches {
+            query_chars.next();
+            first_match.get_or_insert(index);
+
+            run_start.get_or_insert(index);
+            run_end = index + ch.len_utf8();
+            run_chars += 1;
+        } else if let Some(start) = run_start.take() {
+            ranges.push((start, run_end));
+            score += (run_chars * run_chars) as i32;
+            run_chars = 0;
+        }
+    }
+
+    if let Some(start) = run_start.take() {
+        ranges.push((start, run_end));
+        score += (run_chars * run_chars) as i32;
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    let earliness = first_match
+        .map(|index| (candidate.len().saturating_sub(index)) as i32)
+        .unwrap_or(0);
+
+    Some((score * 10 + earliness, ranges))
+}
@@ -0,0 +1,228 @@
+use data::history;
+use iced::widget::pane_grid;
+use iced::widget::{button, column, container, mouse_area, row, text};
+use iced::Length;
+
+use crate::buffer::{self, Buffer};
+use crate::widget::Element;
+
+#[derive(Debug, Clone)]
+pub struct Pane {
+    pub buffers: Vec<Buffer>,
+    pub active: usize,
+    pub settings: buffer::Settings,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    PaneClicked(pane_grid::Pane),
+    PaneResized(pane_grid::ResizeEvent),
+    PaneDragged(pane_grid::DragEvent),
+    ClosePane,
+    SplitPane(pane_grid::Axis),
+    Buffer(pane_grid::Pane, buffer::Message),
+    ToggleShowUserList,
+    MaximizePane,
+    /// Open `kind` as a new tab in the focused pane.
+    NewTab(data::Buffer),
+    SelectTab(usize),
+    CloseTab(usize),
+    /// Pop a tab out of this pane and into a new split alongside it.
+    MoveTabToSplit(usize, pane_grid::Axis),
+    /// Pop the active tab out of the tiled grid and onto the floating layer.
+    FloatPane,
+}
+
+impl Pane {
+    pub fn new(buffer: Buffer, settings: buffer::Settings) -> Self {
+        Self {
+            buffers: vec![buffer],
+            active: 0,
+            settings,
+        }
+    }
+
+    pub fn active(&self) -> &Buffer {
+        &self.buffers[self.active]
+    }
+
+    pub fn active_mut(&mut self) -> &mut Buffer {
+        &mut self.buffers[self.active]
+    }
+
+    pub fn update_settings(&mut self, f: impl FnOnce(&mut buffer::Settings)) {
+        f(&mut self.settings);
+    }
+
+    /// Appends `buffer` as a new tab and selects it.
+    pub fn new_tab(&mut self, buffer: Buffer, settings: buffer::Settings) {
+        self.buffers.push(buffer);
+        self.active = self.buffers.len() - 1;
+        self.settings = settings;
+    }
+
+    pub fn select_tab(&mut self, index: usize) {
+        self.active = index.min(self.buffers.len().saturating_sub(1));
+    }
+
+    /// Closes the tab at `index`, falling back to a single empty buffer
+    /// rather than leaving the pane without any tabs at all.
+    pub fn close_tab(&mut self, index: usize) {
+        if index >= self.buffers.len() {
+            return;
+        }
+
+        if self.buffers.len() == 1 {
+            self.reset_to_empty();
+            return;
+        }
+
+        self.buffers.remove(index);
+        self.active = self.active.min(self.buffers.len() - 1);
+    }
+
+    /// Removes and returns the tab at `index`, backfilling an empty buffer
+    /// if it was the last one so the pane is never left without any tabs.
+    pub fn take_tab(&mut self, index: usize) -> Option<Buffer> {
+        if index >= self.buffers.len() {
+            return None;
+        }
+
+        let buffer = self.buffers.remove(index);
+
+        if self.buffers.is_empty() {
+            self.buffers.push(Buffer::empty());
+        }
+
+        self.active = self.active.min(self.buffers.len() - 1);
+
+        Some(buffer)
+    }
+
+    pub fn replace_active(&mut self, buffer: Buffer) {
+        let active = self.active;
+        self.buffers[active] = buffer;
+    }
+
+    /// Resets this pane back to a single, freshly-created empty buffer.
+    pub fn reset_to_empty(&mut self) {
+        self.buffers = vec![Buffer::empty()];
+        self.active = 0;
+        self.settings = buffer::Settings::default();
+    }
+
+    /// History resources for every tab, not just the active one, so
+    /// background tabs keep receiving updates.
+    pub fn resources(&self) -> impl Iterator<Item = history::Resource> + '_ {
+        self.buffers
+            .iter()
+            .filter_map(Buffer::data)
+            .map(history::Resource::from)
+    }
+
+    pub fn view<'a>(
+        &'a self,
+        id: pane_grid::Pane,
+        panes: usize,
+        is_focused: bool,
+        is_maximized: bool,
+        clients: &'a data::client::Map,
+        history: &'a history::Manager,
+    ) -> pane_grid::Content<'a, super::Message> {
+        let tabs = row(self.buffers.iter().enumerate().map(|(index, buffer)| {
+            let is_active = index == self.active;
+
+            let label = mouse_area(
+                container(text(buffer.title(clients)))
+                    .padding([2, 6])
+                    .style(move |theme: &iced::Theme| {
+                        let palette = theme.extended_palette();
+
+                        container::Style::default().background(if is_active {
+                            palette.background.base.color
+                        } else {
+                            palette.background.weak.color
+                        })
+                    }),
+            )
+            .on_press(super::Message::Pane(Message::SelectTab(index)));
+
+            row![
+                label,
+                button(text("x"))
+                    .padding(2)
+                    .on_press(super::Message::Pane(Message::CloseTab(index)))
+            ]
+            .spacing(2)
+            .into()
+        }))
+        .spacing(2);
+
+        let mut controls = row![
+            button(text("users"))
+                .padding(2)
+                .on_press(super::Message::Pane(Message::ToggleShowUserList)),
+            button(text("float"))
+                .padding(2)
+                .on_press(super::Message::Pane(Message::FloatPane)),
+        ]
+        .spacing(2);
+
+        if panes > 1 {
+            controls = controls.push(
+                button(text(if is_maximized { "restore" } else { "maximize" }))
+                    .padding(2)
+                    .on_press(super::Message::Pane(Message::MaximizePane)),
+            );
+        }
+
+        controls = controls
+            .push(
+                button(text("split"))
+                    .padding(2)
+                    .on_press(super::Message::Pane(Message::SplitPane(
+                        pane_grid::Axis::Horizontal,
+                    ))),
+            )
+            .push(
+                button(text("close"))
+                    .padding(2)
+                    .on_press(super::Message::Pane(Message::ClosePane)),
+            );
+
+        let title_bar = pane_grid::TitleBar::new(
+            row![tabs, container(controls).width(Length::Fill)].spacing(4),
+        )
+        .padding(4)
+        .style(move |theme: &iced::Theme| {
+            let palette = theme.extended_palette();
+
+            container::Style::default().background(if is_focused {
+                palette.background.weak.color
+            } else {
+                palette.background.base.color
+            })
+        });
+
+        let body = self
+            .active()
+            .view(clients, history, &self.settings, is_focused)
+            .map(move |message| super::Message::Pane(Message::Buffer(id, message)));
+
+        pane_grid::Content::new(container(body).width(Length::Fill).height(Length::Fill))
+            .title_bar(title_bar)
+            .style(move |theme: &iced::Theme| {
+                let palette = theme.extended_palette();
+
+                container::Style::default().border(iced::Border {
+                    color: if is_focused {
+                        palette.primary.strong.color
+                    } else {
+                        palette.background.strong.color
+                    },
+                    width: 1.0,
+                    radius: 4.0.into(),
+                })
+            })
+    }
+}